@@ -0,0 +1,113 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use std::io::{BufRead, BufReader};
+use std::process::{Child, Command, Stdio};
+use std::time::{Duration, Instant};
+
+/// Stay fully buffered until this many lines have been collected...
+const BUFFER_LINE_THRESHOLD: usize = 1000;
+/// ...or until this much wall-clock time has elapsed, whichever comes first.
+const BUFFER_DEADLINE: Duration = Duration::from_millis(100);
+
+/// Buffer-then-stream stdout consumer (as in fd's receiver): collect lines into a
+/// `Vec` until `BUFFER_LINE_THRESHOLD` lines or `BUFFER_DEADLINE`, then flush and
+/// stream each later line to the sink. Returns first-line latency and line count.
+fn consume_adaptive(mut child: Child) -> (Option<Duration>, usize) {
+    let stdout = child.stdout.take().expect("Failed to open stdout");
+    let reader = BufReader::new(stdout);
+
+    let start = Instant::now();
+    let mut buffered: Vec<String> = Vec::new();
+    let mut streaming = false;
+    let mut first_line_latency = None;
+    let mut total = 0usize;
+
+    for line in reader.lines() {
+        let line = line.expect("Failed to read line");
+        if first_line_latency.is_none() {
+            first_line_latency = Some(start.elapsed());
+        }
+        total += 1;
+
+        if streaming {
+            // Streaming mode: forward straight to the sink for minimal latency.
+            black_box(&line);
+        } else {
+            buffered.push(line);
+            if buffered.len() >= BUFFER_LINE_THRESHOLD || start.elapsed() >= BUFFER_DEADLINE {
+                // Threshold crossed: drain what we have and flip to streaming.
+                for line in buffered.drain(..) {
+                    black_box(line);
+                }
+                streaming = true;
+            }
+        }
+    }
+
+    // If the child stayed under the threshold the whole run sits in `buffered`,
+    // ready for ordering or post-processing.
+    black_box(&buffered);
+
+    child.wait().expect("Failed to wait for command");
+    (first_line_latency, total)
+}
+
+/// A child whose output fits under the line threshold, so the consumer stays in
+/// buffered mode unless the output takes longer than `BUFFER_DEADLINE` to arrive.
+fn short_output_child() -> Child {
+    Command::new("dotnet")
+        .arg("--help")
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .expect("Failed to spawn command")
+}
+
+/// A child that emits far more than the threshold number of lines, forcing the
+/// consumer to flip into streaming mode partway through.
+fn long_output_child() -> Child {
+    #[cfg(target_os = "windows")]
+    {
+        Command::new("cmd")
+            .args(["/c", "for /L %i in (1,1,100000) do @echo %i"])
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()
+            .expect("Failed to spawn command")
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        Command::new("sh")
+            .args(["-c", "seq 1 100000"])
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()
+            .expect("Failed to spawn command")
+    }
+}
+
+/// Short output stays fully buffered when it arrives within `BUFFER_DEADLINE`,
+/// enabling sorting/post-processing.
+fn adaptive_output_short(c: &mut Criterion) {
+    c.bench_function("adaptive_output_short", |b| {
+        b.iter(|| {
+            let (first_line, total) = consume_adaptive(short_output_child());
+            black_box(first_line);
+            black_box(total);
+        });
+    });
+}
+
+/// Long output crosses the threshold and flips into streaming mode.
+fn adaptive_output_long(c: &mut Criterion) {
+    c.bench_function("adaptive_output_long", |b| {
+        b.iter(|| {
+            let (first_line, total) = consume_adaptive(long_output_child());
+            black_box(first_line);
+            black_box(total);
+        });
+    });
+}
+
+criterion_group!(benches, adaptive_output_short, adaptive_output_long);
+criterion_main!(benches);
@@ -0,0 +1,76 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use tokio::io::AsyncReadExt;
+use tokio::process::Command;
+use tokio::runtime::Runtime;
+use std::process::Stdio;
+
+/// Number of children spawned concurrently by the multiplexing variant.
+const CONCURRENT_CHILDREN: usize = 8;
+
+/// Spawns a child and drains stdout and stderr concurrently with `tokio::join!`.
+async fn spawn_and_drain() -> Option<i32> {
+    let mut child = Command::new("dotnet")
+        .arg("--help")
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("Failed to spawn command");
+
+    let mut stdout = child.stdout.take().expect("Failed to open stdout");
+    let mut stderr = child.stderr.take().expect("Failed to open stderr");
+
+    let mut stdout_buf = Vec::new();
+    let mut stderr_buf = Vec::new();
+
+    let (stdout_res, stderr_res) = tokio::join!(
+        stdout.read_to_end(&mut stdout_buf),
+        stderr.read_to_end(&mut stderr_buf),
+    );
+    stdout_res.expect("Failed to read stdout");
+    stderr_res.expect("Failed to read stderr");
+
+    let status = child.wait().await.expect("Failed to wait for command");
+
+    black_box(&stdout_buf);
+    black_box(&stderr_buf);
+    status.code()
+}
+
+/// Benchmarks spawning and draining a single child asynchronously. The runtime is
+/// built once outside `b.iter` so the measurement excludes runtime construction.
+fn async_spawn_single(c: &mut Criterion) {
+    let rt = Runtime::new().expect("Failed to build tokio runtime");
+
+    c.bench_function("async_spawn_single", |b| {
+        b.iter(|| {
+            let code = rt.block_on(spawn_and_drain());
+            black_box(code);
+        });
+    });
+}
+
+/// Benchmarks spawning `CONCURRENT_CHILDREN` children at once and awaiting them
+/// all — async I/O multiplexing many children on a small thread pool.
+fn async_spawn_concurrent(c: &mut Criterion) {
+    let rt = Runtime::new().expect("Failed to build tokio runtime");
+
+    c.bench_function("async_spawn_concurrent", |b| {
+        b.iter(|| {
+            let codes = rt.block_on(async {
+                let handles: Vec<_> = (0..CONCURRENT_CHILDREN)
+                    .map(|_| tokio::spawn(spawn_and_drain()))
+                    .collect();
+
+                let mut codes = Vec::with_capacity(handles.len());
+                for handle in handles {
+                    codes.push(handle.await.expect("Child task panicked"));
+                }
+                codes
+            });
+            black_box(codes);
+        });
+    });
+}
+
+criterion_group!(benches, async_spawn_single, async_spawn_concurrent);
+criterion_main!(benches);
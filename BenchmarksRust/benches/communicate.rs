@@ -0,0 +1,206 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use std::process::{Command, Stdio};
+
+/// Input sizes below and above a typical pipe's kernel buffer (~64 KiB), where
+/// the naive "write all then read all" ordering would deadlock.
+const INPUT_SIZES: &[(&str, usize)] = &[("1KiB", 1024), ("1MiB", 1024 * 1024)];
+
+/// Feeds `input` to stdin while draining stdout and stderr, closing stdin at EOF.
+/// The writes and reads share a single `poll()` loop (stdin on `POLLOUT`, the
+/// outputs on `POLLIN`) so a full pipe buffer never deadlocks the thread.
+#[cfg(unix)]
+fn communicate(input: &[u8]) -> (Vec<u8>, Vec<u8>, Option<i32>) {
+    use std::os::fd::AsRawFd;
+
+    // `cat` echoes stdin back to stdout, so the input genuinely flows through.
+    let mut child = Command::new("cat")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("Failed to spawn command");
+
+    let stdin = child.stdin.take().expect("Failed to open stdin");
+    let stdout = child.stdout.take().expect("Failed to open stdout");
+    let stderr = child.stderr.take().expect("Failed to open stderr");
+
+    let stdin_fd = stdin.as_raw_fd();
+    let out_fd = stdout.as_raw_fd();
+    let err_fd = stderr.as_raw_fd();
+    for &fd in &[stdin_fd, out_fd, err_fd] {
+        set_nonblocking(fd);
+    }
+
+    // Keep stdin in an `Option` so it can be dropped (closed) mid-loop to signal EOF.
+    let mut stdin = Some(stdin);
+    let mut written = 0usize;
+    let mut out_buf = Vec::new();
+    let mut err_buf = Vec::new();
+    let mut out_done = false;
+    let mut err_done = false;
+    let mut chunk = [0u8; 8 * 1024];
+
+    if input.is_empty() {
+        stdin = None;
+    }
+
+    while stdin.is_some() || !(out_done && err_done) {
+        let mut pfds = [
+            libc::pollfd {
+                fd: if stdin.is_some() { stdin_fd } else { -1 },
+                events: libc::POLLOUT,
+                revents: 0,
+            },
+            libc::pollfd {
+                fd: if out_done { -1 } else { out_fd },
+                events: libc::POLLIN,
+                revents: 0,
+            },
+            libc::pollfd {
+                fd: if err_done { -1 } else { err_fd },
+                events: libc::POLLIN,
+                revents: 0,
+            },
+        ];
+
+        let ready = unsafe { libc::poll(pfds.as_mut_ptr(), pfds.len() as _, -1) };
+        if ready < 0 {
+            let err = std::io::Error::last_os_error();
+            if err.kind() == std::io::ErrorKind::Interrupted {
+                continue;
+            }
+            panic!("poll failed: {}", err);
+        }
+
+        // Write a chunk whenever stdin is writable (or has hung up).
+        if stdin.is_some() && pfds[0].revents & (libc::POLLOUT | libc::POLLERR | libc::POLLHUP) != 0 {
+            let remaining = &input[written..];
+            let want = remaining.len().min(64 * 1024);
+            let n = unsafe {
+                libc::write(stdin_fd, remaining.as_ptr() as *const libc::c_void, want)
+            };
+            if n > 0 {
+                written += n as usize;
+            } else if n < 0 {
+                let err = std::io::Error::last_os_error();
+                match err.kind() {
+                    std::io::ErrorKind::WouldBlock | std::io::ErrorKind::Interrupted => {}
+                    std::io::ErrorKind::BrokenPipe => stdin = None,
+                    _ => panic!("write to stdin failed: {}", err),
+                }
+            }
+            if stdin.is_some() && written == input.len() {
+                stdin = None;
+            }
+        }
+
+        // Drain whichever output streams are readable.
+        for (i, (fd, buf, done)) in [
+            (out_fd, &mut out_buf, &mut out_done),
+            (err_fd, &mut err_buf, &mut err_done),
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            let revents = pfds[i + 1].revents;
+            if *done || revents & (libc::POLLIN | libc::POLLHUP) == 0 {
+                continue;
+            }
+            loop {
+                let n = unsafe {
+                    libc::read(fd, chunk.as_mut_ptr() as *mut libc::c_void, chunk.len())
+                };
+                if n > 0 {
+                    buf.extend_from_slice(&chunk[..n as usize]);
+                } else if n == 0 {
+                    *done = true;
+                    break;
+                } else {
+                    let err = std::io::Error::last_os_error();
+                    match err.kind() {
+                        std::io::ErrorKind::WouldBlock => break,
+                        std::io::ErrorKind::Interrupted => continue,
+                        _ => panic!("read failed: {}", err),
+                    }
+                }
+            }
+        }
+    }
+
+    let status = child.wait().expect("Failed to wait for command");
+    (out_buf, err_buf, status.code())
+}
+
+/// Sets `O_NONBLOCK` on a descriptor via `fcntl`.
+#[cfg(unix)]
+fn set_nonblocking(fd: std::os::fd::RawFd) {
+    unsafe {
+        let flags = libc::fcntl(fd, libc::F_GETFL);
+        assert!(flags != -1, "fcntl(F_GETFL) failed: {}", std::io::Error::last_os_error());
+        let res = libc::fcntl(fd, libc::F_SETFL, flags | libc::O_NONBLOCK);
+        assert!(res != -1, "fcntl(F_SETFL) failed: {}", std::io::Error::last_os_error());
+    }
+}
+
+/// Windows std pipes are blocking, so feed stdin from a writer thread while two
+/// reader threads drain the outputs — the thread-based analogue of the poll loop.
+#[cfg(windows)]
+fn communicate(input: &[u8]) -> (Vec<u8>, Vec<u8>, Option<i32>) {
+    use std::io::{Read, Write};
+    use std::thread;
+
+    // `findstr "^"` matches every line, echoing stdin back out through stdout.
+    let mut child = Command::new("findstr")
+        .arg("^")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("Failed to spawn command");
+
+    let mut stdin = child.stdin.take().expect("Failed to open stdin");
+    let mut stdout = child.stdout.take().expect("Failed to open stdout");
+    let mut stderr = child.stderr.take().expect("Failed to open stderr");
+
+    let input = input.to_vec();
+    let writer = thread::spawn(move || {
+        // A child that never reads its stdin may close early; ignore the broken pipe.
+        let _ = stdin.write_all(&input);
+        drop(stdin);
+    });
+    let out_handle = thread::spawn(move || {
+        let mut buf = Vec::new();
+        stdout.read_to_end(&mut buf).expect("Failed to read stdout");
+        buf
+    });
+    let err_handle = thread::spawn(move || {
+        let mut buf = Vec::new();
+        stderr.read_to_end(&mut buf).expect("Failed to read stderr");
+        buf
+    });
+
+    writer.join().expect("Failed to join stdin writer thread");
+    let out_buf = out_handle.join().expect("Failed to join stdout thread");
+    let err_buf = err_handle.join().expect("Failed to join stderr thread");
+
+    let status = child.wait().expect("Failed to wait for command");
+    (out_buf, err_buf, status.code())
+}
+
+/// Benchmarks the deadlock-free communicate pattern across input sizes.
+fn communicate_sizes(c: &mut Criterion) {
+    for &(name, size) in INPUT_SIZES {
+        let input = vec![b'x'; size];
+        c.bench_function(&format!("communicate_{}", name), |b| {
+            b.iter(|| {
+                let (out_buf, err_buf, code) = communicate(&input);
+                black_box(out_buf);
+                black_box(err_buf);
+                black_box(code);
+            });
+        });
+    }
+}
+
+criterion_group!(benches, communicate_sizes);
+criterion_main!(benches);
@@ -0,0 +1,91 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use std::io::Write;
+use std::process::{Command, Stdio};
+use std::thread;
+
+/// A passthrough tail process for the pipeline; the parent only reads its output.
+#[cfg(not(target_os = "windows"))]
+fn tail_command() -> Command {
+    Command::new("cat")
+}
+
+#[cfg(target_os = "windows")]
+fn tail_command() -> Command {
+    // `findstr "^"` matches every line, so it copies stdin to stdout unchanged.
+    let mut cmd = Command::new("findstr");
+    cmd.arg("^");
+    cmd
+}
+
+/// Benchmarks chaining two processes without a shell: the first child's
+/// `ChildStdout` is handed to the second as stdin via `Stdio::from`, so the kernel
+/// copies the intermediate stream with no user-space hop.
+fn pipeline_os(c: &mut Criterion) {
+    c.bench_function("pipeline_os", |b| {
+        b.iter(|| {
+            let mut first = Command::new("dotnet")
+                .arg("--help")
+                .stdout(Stdio::piped())
+                .spawn()
+                .expect("Failed to spawn first command");
+
+            let first_out = first.stdout.take().expect("Failed to open first stdout");
+
+            let second = tail_command()
+                .stdin(Stdio::from(first_out))
+                .stdout(Stdio::piped())
+                .spawn()
+                .expect("Failed to spawn second command");
+
+            let output = second
+                .wait_with_output()
+                .expect("Failed to wait for second command");
+            let first_status = first.wait().expect("Failed to wait for first command");
+
+            black_box(output.stdout.len());
+            black_box(first_status.code());
+            black_box(output.status.code());
+        });
+    });
+}
+
+/// Benchmarks the user-space equivalent: the first child's output is read into a
+/// `Vec` and written into the second child's stdin (from a writer thread so the
+/// copy can't deadlock). The gap against `pipeline_os` is the extra user-space hop.
+fn pipeline_userspace(c: &mut Criterion) {
+    c.bench_function("pipeline_userspace", |b| {
+        b.iter(|| {
+            let first = Command::new("dotnet")
+                .arg("--help")
+                .output()
+                .expect("Failed to run first command");
+
+            let mut second = tail_command()
+                .stdin(Stdio::piped())
+                .stdout(Stdio::piped())
+                .spawn()
+                .expect("Failed to spawn second command");
+
+            let mut stdin = second.stdin.take().expect("Failed to open second stdin");
+            let intermediate = first.stdout;
+            let writer = thread::spawn(move || {
+                stdin
+                    .write_all(&intermediate)
+                    .expect("Failed to write to second stdin");
+                drop(stdin);
+            });
+
+            let output = second
+                .wait_with_output()
+                .expect("Failed to wait for second command");
+            writer.join().expect("Failed to join writer thread");
+
+            black_box(output.stdout.len());
+            black_box(first.status.code());
+            black_box(output.status.code());
+        });
+    });
+}
+
+criterion_group!(benches, pipeline_os, pipeline_userspace);
+criterion_main!(benches);
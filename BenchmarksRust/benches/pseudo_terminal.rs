@@ -0,0 +1,60 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use portable_pty::{native_pty_system, CommandBuilder, PtySize};
+use std::io::Read;
+
+/// Benchmarks spawning the child on a pseudo-terminal and reading the master side.
+/// A TTY on stdout makes the child line-buffer instead of block-buffer, and this
+/// also measures the pty allocation overhead versus the `Stdio::piped()` paths.
+fn pty_spawn_read(c: &mut Criterion) {
+    let pty_system = native_pty_system();
+
+    c.bench_function("pty_spawn_read", |b| {
+        b.iter(|| {
+            let pair = pty_system
+                .openpty(PtySize {
+                    rows: 24,
+                    cols: 80,
+                    pixel_width: 0,
+                    pixel_height: 0,
+                })
+                .expect("Failed to open pty");
+
+            let mut cmd = CommandBuilder::new("dotnet");
+            cmd.arg("--help");
+
+            let mut child = pair
+                .slave
+                .spawn_command(cmd)
+                .expect("Failed to spawn command on pty");
+
+            // Clone a reader for the master, then drop the slave so the master
+            // observes EOF once the child exits and releases its controlling tty.
+            let mut reader = pair
+                .master
+                .try_clone_reader()
+                .expect("Failed to clone pty reader");
+            drop(pair.slave);
+
+            let mut buf = Vec::new();
+            let mut chunk = [0u8; 8 * 1024];
+            loop {
+                match reader.read(&mut chunk) {
+                    Ok(0) => break,
+                    Ok(n) => buf.extend_from_slice(&chunk[..n]),
+                    // Linux returns EIO on a pty master once the slave is closed; treat as EOF.
+                    Err(e) if e.raw_os_error() == Some(5) => break,
+                    Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+                    Err(e) => panic!("Failed to read from pty: {}", e),
+                }
+            }
+
+            let status = child.wait().expect("Failed to wait for command");
+
+            black_box(buf);
+            black_box(status.success());
+        });
+    });
+}
+
+criterion_group!(benches, pty_spawn_read);
+criterion_main!(benches);
@@ -107,6 +107,166 @@ fn redirect_to_pipe_concurrent(c: &mut Criterion) {
     });
 }
 
+/// Benchmarks draining both pipes from a single thread via `poll()`, like
+/// libstd's internal `read2`, instead of the two draining threads in the concurrent case.
+fn redirect_to_pipe_poll(c: &mut Criterion) {
+    c.bench_function("redirect_to_pipe_poll", |b| {
+        b.iter(|| {
+            let mut child = Command::new("dotnet")
+                .arg("--help")
+                .stdout(Stdio::piped())
+                .stderr(Stdio::piped())
+                .spawn()
+                .expect("Failed to spawn command");
+
+            let stdout = child.stdout.take().expect("Failed to open stdout");
+            let stderr = child.stderr.take().expect("Failed to open stderr");
+
+            let (stdout_buf, stderr_buf) = drain_both_poll(stdout, stderr);
+
+            let status = child.wait().expect("Failed to wait for command");
+
+            black_box(stdout_buf);
+            black_box(stderr_buf);
+            black_box(status.code());
+        });
+    });
+}
+
+/// Reads both streams to EOF from one thread: set them non-blocking, `poll()` for
+/// `POLLIN`, drain each readable fd until `EWOULDBLOCK`, drop it from the set at EOF.
+#[cfg(unix)]
+fn drain_both_poll(
+    stdout: std::process::ChildStdout,
+    stderr: std::process::ChildStderr,
+) -> (Vec<u8>, Vec<u8>) {
+    use std::os::fd::AsRawFd;
+
+    let fds = [stdout.as_raw_fd(), stderr.as_raw_fd()];
+    for &fd in &fds {
+        set_nonblocking(fd);
+    }
+
+    let mut bufs = [Vec::new(), Vec::new()];
+    let mut done = [false, false];
+    let mut chunk = [0u8; 8 * 1024];
+
+    while !(done[0] && done[1]) {
+        let mut poll_fds = [
+            libc::pollfd { fd: fds[0], events: libc::POLLIN, revents: 0 },
+            libc::pollfd { fd: fds[1], events: libc::POLLIN, revents: 0 },
+        ];
+        // A negative fd is ignored by poll(), so drop finished streams from the set.
+        for i in 0..2 {
+            if done[i] {
+                poll_fds[i].fd = -1;
+            }
+        }
+
+        let ready = unsafe { libc::poll(poll_fds.as_mut_ptr(), poll_fds.len() as _, -1) };
+        if ready < 0 {
+            let err = std::io::Error::last_os_error();
+            if err.kind() == std::io::ErrorKind::Interrupted {
+                continue;
+            }
+            panic!("poll failed: {}", err);
+        }
+
+        for i in 0..2 {
+            if done[i] || poll_fds[i].revents & (libc::POLLIN | libc::POLLHUP) == 0 {
+                continue;
+            }
+            loop {
+                let n = unsafe {
+                    libc::read(fds[i], chunk.as_mut_ptr() as *mut libc::c_void, chunk.len())
+                };
+                if n > 0 {
+                    bufs[i].extend_from_slice(&chunk[..n as usize]);
+                } else if n == 0 {
+                    done[i] = true;
+                    break;
+                } else {
+                    let err = std::io::Error::last_os_error();
+                    match err.kind() {
+                        std::io::ErrorKind::WouldBlock => break,
+                        std::io::ErrorKind::Interrupted => continue,
+                        _ => panic!("read failed: {}", err),
+                    }
+                }
+            }
+        }
+    }
+
+    let [stdout_buf, stderr_buf] = bufs;
+    (stdout_buf, stderr_buf)
+}
+
+/// Windows `Stdio::piped()` pipes aren't overlapped, so the single-threaded
+/// `ReadFile` loop isn't available; fall back to one draining thread per stream.
+#[cfg(windows)]
+fn drain_both_poll(
+    stdout: std::process::ChildStdout,
+    stderr: std::process::ChildStderr,
+) -> (Vec<u8>, Vec<u8>) {
+    let stdout_handle = thread::spawn(move || {
+        let mut buf = Vec::new();
+        let mut stdout = stdout;
+        stdout.read_to_end(&mut buf).expect("Failed to read stdout");
+        buf
+    });
+    let stderr_handle = thread::spawn(move || {
+        let mut buf = Vec::new();
+        let mut stderr = stderr;
+        stderr.read_to_end(&mut buf).expect("Failed to read stderr");
+        buf
+    });
+
+    let stdout_buf = stdout_handle.join().expect("Failed to join stdout thread");
+    let stderr_buf = stderr_handle.join().expect("Failed to join stderr thread");
+    (stdout_buf, stderr_buf)
+}
+
+/// Sets `O_NONBLOCK` on a raw file descriptor via `fcntl`.
+#[cfg(unix)]
+fn set_nonblocking(fd: std::os::fd::RawFd) {
+    unsafe {
+        let flags = libc::fcntl(fd, libc::F_GETFL);
+        assert!(flags != -1, "fcntl(F_GETFL) failed: {}", std::io::Error::last_os_error());
+        let res = libc::fcntl(fd, libc::F_SETFL, flags | libc::O_NONBLOCK);
+        assert!(res != -1, "fcntl(F_SETFL) failed: {}", std::io::Error::last_os_error());
+    }
+}
+
+/// Benchmarks capturing stdout and stderr through a single pipe: the same write
+/// end is handed to both streams, so the parent drains one descriptor and needs
+/// no concurrent readers, at the cost of the stdout/stderr distinction.
+fn redirect_to_pipe_merged(c: &mut Criterion) {
+    c.bench_function("redirect_to_pipe_merged", |b| {
+        b.iter(|| {
+            let (mut reader, writer) = os_pipe::pipe().expect("Failed to create pipe");
+            let writer_clone = writer.try_clone().expect("Failed to clone pipe writer");
+
+            let mut child = Command::new("dotnet")
+                .arg("--help")
+                .stdout(Stdio::from(writer))
+                .stderr(Stdio::from(writer_clone))
+                .spawn()
+                .expect("Failed to spawn command");
+
+            // The temporary `Command` owned the parent's write ends and was dropped
+            // at the end of the statement above, so the read end sees EOF once the
+            // child exits (a retained write handle would hang the read).
+            let mut buf = Vec::new();
+            reader.read_to_end(&mut buf).expect("Failed to read merged output");
+
+            let status = child.wait().expect("Failed to wait for command");
+
+            black_box(buf);
+            black_box(status.code());
+        });
+    });
+}
+
 /// Benchmarks reading entire output at once using read_to_end.
 fn redirect_to_pipe_read_all(c: &mut Criterion) {
     c.bench_function("redirect_to_pipe_read_all", |b| {
@@ -141,6 +301,8 @@ criterion_group!(
     redirect_to_pipe_lines,
     redirect_to_pipe_output,
     redirect_to_pipe_concurrent,
+    redirect_to_pipe_poll,
+    redirect_to_pipe_merged,
     redirect_to_pipe_read_all
 );
 criterion_main!(benches);